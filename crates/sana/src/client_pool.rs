@@ -0,0 +1,159 @@
+//! A pool of Starknet RPC endpoints with automatic failover.
+//!
+//! Indexing throughput (and uptime) shouldn't hinge on a single RPC node.
+//! `StarknetClientPool` wraps an ordered list of endpoints, each with its
+//! own client and backoff state, and transparently retries the next
+//! healthy endpoint whenever a call fails for a transport reason rather
+//! than because the chain legitimately has nothing to return.
+
+use ark_starknet::client::{StarknetClient, StarknetClientError};
+use std::future::Future;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+use tracing::{error, warn};
+
+/// How long an endpoint is skipped after a transport failure before it's
+/// considered for retries again.
+const ENDPOINT_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Builds a client from a bare RPC endpoint URL. Implemented by whatever
+/// `StarknetClient` the indexer is instantiated with, so
+/// `StarknetClientPool` can turn a list of configured URLs into one
+/// client per endpoint.
+pub trait FromEndpoint: Sized {
+    fn from_endpoint(url: &str) -> Result<Self, StarknetClientError>;
+}
+
+struct Endpoint<C> {
+    url: String,
+    client: Arc<C>,
+    backing_off_until: Option<Instant>,
+}
+
+/// Whether a provider error is worth failing over for, as opposed to a
+/// legitimate response (e.g. "block not found") that every endpoint would
+/// give the same way.
+fn is_transport_error(err: &StarknetClientError) -> bool {
+    let msg = err.to_string().to_lowercase();
+    !(msg.contains("block not found") || msg.contains("block is not found"))
+}
+
+/// Ordered collection of Starknet RPC endpoints, used in place of a
+/// single `Arc<C>` so a dead node doesn't stall indexing.
+pub struct StarknetClientPool<C> {
+    endpoints: RwLock<Vec<Endpoint<C>>>,
+    next: AtomicUsize,
+}
+
+impl<C: StarknetClient + FromEndpoint> StarknetClientPool<C> {
+    /// Builds one client per endpoint URL, in the given order. The first
+    /// endpoint is treated as primary for callers that only need a
+    /// single representative client (e.g. `TokenManager`, `ContractManager`).
+    ///
+    /// Errors if `urls` is empty: `primary_client` and `healthy_endpoint_urls`
+    /// both assume at least one configured endpoint, so an empty pool is
+    /// rejected here rather than panicking the first time either is called.
+    pub fn new(urls: &[String]) -> Result<Self, StarknetClientError> {
+        if urls.is_empty() {
+            return Err(StarknetClientError::Other(
+                "at least one Starknet RPC endpoint is required".to_string(),
+            ));
+        }
+
+        let endpoints = urls
+            .iter()
+            .map(|url| {
+                Ok(Endpoint {
+                    url: url.clone(),
+                    client: Arc::new(C::from_endpoint(url)?),
+                    backing_off_until: None,
+                })
+            })
+            .collect::<Result<Vec<_>, StarknetClientError>>()?;
+
+        Ok(Self {
+            endpoints: RwLock::new(endpoints),
+            next: AtomicUsize::new(0),
+        })
+    }
+
+    /// The primary (first configured) endpoint's client.
+    pub fn primary_client(&self) -> Arc<C> {
+        Arc::clone(&self.endpoints.read().unwrap()[0].client)
+    }
+
+    /// URLs of every endpoint not currently backing off, in failover
+    /// order starting from the next endpoint due to be tried.
+    pub fn healthy_endpoint_urls(&self) -> Vec<String> {
+        let endpoints = self.endpoints.read().unwrap();
+        let len = endpoints.len();
+        let start = self.next.load(Ordering::Relaxed) % len.max(1);
+
+        (0..len)
+            .map(|offset| &endpoints[(start + offset) % len])
+            .filter(|e| !Self::is_backing_off(e))
+            .map(|e| e.url.clone())
+            .collect()
+    }
+
+    fn is_backing_off(endpoint: &Endpoint<C>) -> bool {
+        endpoint
+            .backing_off_until
+            .map(|until| Instant::now() < until)
+            .unwrap_or(false)
+    }
+
+    fn mark_failed(&self, index: usize) {
+        let mut endpoints = self.endpoints.write().unwrap();
+        if let Some(endpoint) = endpoints.get_mut(index) {
+            warn!("Marking Starknet RPC endpoint {} as unhealthy", endpoint.url);
+            endpoint.backing_off_until = Some(Instant::now() + ENDPOINT_BACKOFF);
+        }
+    }
+
+    /// Runs `f` against each healthy endpoint in turn, starting from a
+    /// rotating offset so load spreads across the pool. Fails over on any
+    /// transport error; a genuine provider error (e.g. "block not found")
+    /// is returned immediately since every endpoint would answer the same
+    /// way. Returns the last transport error once every endpoint has been
+    /// tried or skipped, or a "no healthy endpoint" error if every
+    /// endpoint was skipped because it is backing off.
+    pub async fn call<T, F, Fut>(&self, f: F) -> Result<T, StarknetClientError>
+    where
+        F: Fn(Arc<C>) -> Fut,
+        Fut: Future<Output = Result<T, StarknetClientError>>,
+    {
+        let len = self.endpoints.read().unwrap().len();
+        let start = self.next.fetch_add(1, Ordering::Relaxed) % len.max(1);
+        let mut last_err = None;
+
+        for offset in 0..len {
+            let index = (start + offset) % len;
+
+            let (client, skip) = {
+                let endpoints = self.endpoints.read().unwrap();
+                let endpoint = &endpoints[index];
+                (Arc::clone(&endpoint.client), Self::is_backing_off(endpoint))
+            };
+
+            if skip {
+                continue;
+            }
+
+            match f(client).await {
+                Ok(value) => return Ok(value),
+                Err(err) if is_transport_error(&err) => {
+                    error!("Transport error on Starknet RPC endpoint #{}: {:?}", index, err);
+                    self.mark_failed(index);
+                    last_err = Some(err);
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        Err(last_err.unwrap_or_else(|| {
+            StarknetClientError::Other("no healthy Starknet RPC endpoint available".to_string())
+        }))
+    }
+}
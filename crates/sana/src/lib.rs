@@ -1,31 +1,32 @@
+pub mod client_pool;
 pub mod event_handler;
 pub mod managers;
+pub mod processors;
+pub mod query;
 pub mod storage;
 
+use crate::client_pool::{FromEndpoint, StarknetClientPool};
+use crate::processors::{default_processors, EventProcessor, ProcessorContext};
 use crate::storage::types::BlockIndexingStatus;
 use anyhow::Result;
 use ark_starknet::client::{StarknetClient, StarknetClientError};
-use ark_starknet::format::to_hex_str;
 use event_handler::EventHandler;
+use futures::stream::{FuturesOrdered, StreamExt};
 use managers::pending_block_manager::FetchPendingEvents;
 use managers::{BlockManager, ContractManager, EventManager, TokenManager};
 use starknet::core::types::*;
+use std::collections::{HashSet, VecDeque};
 use std::fmt;
 use std::sync::Arc;
-use storage::types::{ContractType, StorageError};
+use storage::types::StorageError;
 use storage::Storage;
+use tokio::sync::Mutex as AsyncMutex;
 use tokio::sync::RwLock as AsyncRwLock;
-use tracing::{debug, error, info, trace, warn};
+use tracing::{error, info, trace, warn};
 pub type IndexerResult<T> = Result<T, IndexerError>;
 use tokio::task::JoinError;
 use tokio::time::Duration;
 
-const ELEMENT_MARKETPLACE_EVENT_HEX: &str =
-    "0x351e5a57ea6ca22e3e3cd212680ef7f3b57404609bda942a5e75ba4724b55e0";
-
-const VENTORY_MARKETPLACE_EVENT_HEX: &str =
-    "0x1b43f40d55364e989b3a8674460f61ba8f327542298ee6240a54ee2bf7b55bb"; // EventListingBought
-
 /// Generic errors for Sana.
 #[derive(Debug)]
 pub enum IndexerError {
@@ -80,27 +81,47 @@ impl fmt::Display for IndexerError {
 
 impl std::error::Error for IndexerError {}
 
+/// How many recent `(block number, block hash)` pairs `Sana` keeps in
+/// memory so that the common case of reorg detection doesn't round-trip
+/// through `Storage` for every block.
+const RECENT_BLOCK_HASHES_CAPACITY: usize = 64;
+
 pub struct SanaConfig {
     pub indexer_version: String,
     pub indexer_identifier: String,
+    /// RPC endpoints to index from, tried in order with failover on
+    /// transport errors. Must contain at least one endpoint.
+    pub rpc_endpoints: Vec<String>,
+    /// How many blocks `index_block_range` fetches at once (the block
+    /// being awaited plus those prefetched after it). `1` reproduces the original
+    /// strictly sequential behavior (fetch, then process, one block at a
+    /// time) for nodes that can't handle parallel load.
+    pub concurrency: usize,
 }
 
 pub struct Sana<S: Storage, C: StarknetClient, E: EventHandler> {
-    client: Arc<C>,
+    client_pool: Arc<StarknetClientPool<C>>,
     event_handler: Arc<E>,
     config: SanaConfig,
     block_manager: Arc<BlockManager<S>>,
     event_manager: Arc<EventManager<S>>,
     token_manager: Arc<TokenManager<S, C>>,
     contract_manager: Arc<AsyncRwLock<ContractManager<S, C>>>,
+    processors: Vec<Box<dyn EventProcessor<S, C>>>,
+    // Sliding window of recently indexed block hashes, used to detect a
+    // reorg without hitting storage on every block. See `known_block_hash`.
+    recent_block_hashes: AsyncMutex<VecDeque<(u64, FieldElement)>>,
 }
 
-impl<S: Storage, C: StarknetClient, E: EventHandler + Send + Sync> Sana<S, C, E> {
+impl<S: Storage, C: StarknetClient + FromEndpoint, E: EventHandler + Send + Sync> Sana<S, C, E> {
     ///
-    pub fn new(client: Arc<C>, storage: Arc<S>, event_handler: Arc<E>, config: SanaConfig) -> Self {
-        Sana {
+    pub fn new(storage: Arc<S>, event_handler: Arc<E>, config: SanaConfig) -> IndexerResult<Self> {
+        let client_pool = Arc::new(StarknetClientPool::new(&config.rpc_endpoints)?);
+        let client = client_pool.primary_client();
+
+        Ok(Sana {
             config,
-            client: Arc::clone(&client),
+            client_pool,
             event_handler: Arc::clone(&event_handler),
             block_manager: Arc::new(BlockManager::new(Arc::clone(&storage))),
             event_manager: Arc::new(EventManager::new(Arc::clone(&storage))),
@@ -112,15 +133,49 @@ impl<S: Storage, C: StarknetClient, E: EventHandler + Send + Sync> Sana<S, C, E>
                 Arc::clone(&storage),
                 Arc::clone(&client),
             ))),
-        }
+            processors: default_processors(),
+            recent_block_hashes: AsyncMutex::new(VecDeque::with_capacity(
+                RECENT_BLOCK_HASHES_CAPACITY,
+            )),
+        })
+    }
+
+    /// Union of every registered processor's `event_keys`, used as the
+    /// selector set fed to `fetch_all_block_events` so adding a processor
+    /// is enough to start receiving its events — no change to the core
+    /// loop required.
+    fn keys_selector(&self) -> Vec<FieldElement> {
+        let mut seen = HashSet::new();
+        self.processors
+            .iter()
+            .flat_map(|p| p.event_keys())
+            .filter(|key| seen.insert(*key))
+            .collect()
     }
 
+    /// Routes the pending-block fetcher through the configured endpoint
+    /// pool instead of a single hardcoded RPC URL, trying each healthy
+    /// endpoint in turn until one succeeds.
     pub async fn index_pending(&self) -> IndexerResult<()> {
-        let fetcher = FetchPendingEvents::new(
-            "https://starknet-mainnet.g.alchemy.com/starknet/version/rpc/v0_7/ssydbI7745ocbNd_c-xULVsq9xXF947b",
-            Duration::from_secs(1),
-        )?;
-        let _ = fetcher.run().await;
+        let endpoints = self.client_pool.healthy_endpoint_urls();
+
+        if endpoints.is_empty() {
+            warn!("No healthy Starknet RPC endpoint available for pending indexing");
+            return Ok(());
+        }
+
+        for url in endpoints {
+            let fetcher = FetchPendingEvents::new(&url, Duration::from_secs(1))?;
+
+            match fetcher.run().await {
+                Ok(()) => return Ok(()),
+                Err(e) => {
+                    error!("Pending fetcher failed on endpoint {}: {:?}", url, e);
+                    continue;
+                }
+            }
+        }
+
         Ok(())
     }
 
@@ -130,6 +185,10 @@ impl<S: Storage, C: StarknetClient, E: EventHandler + Send + Sync> Sana<S, C, E>
     /// If you use this on latest, be sure to don't have any
     /// other sana instance running `index_pending` as you may
     /// deal with overlaps or at least check db registers first.
+    ///
+    /// Dispatches to the pipelined implementation when
+    /// `config.concurrency > 1`, otherwise keeps the exact sequential
+    /// behavior (one block fetched and processed at a time).
     pub async fn index_block_range(
         &self,
         from_block: BlockId,
@@ -137,8 +196,30 @@ impl<S: Storage, C: StarknetClient, E: EventHandler + Send + Sync> Sana<S, C, E>
         force_mode: bool,
         chain_id: &str,
     ) -> IndexerResult<()> {
-        let mut current_u64 = self.client.block_id_to_u64(&from_block).await?;
-        let to_u64 = self.client.block_id_to_u64(&to_block).await?;
+        if self.config.concurrency > 1 {
+            self.index_block_range_pipelined(from_block, to_block, force_mode, chain_id)
+                .await
+        } else {
+            self.index_block_range_sequential(from_block, to_block, force_mode, chain_id)
+                .await
+        }
+    }
+
+    async fn index_block_range_sequential(
+        &self,
+        from_block: BlockId,
+        to_block: BlockId,
+        force_mode: bool,
+        chain_id: &str,
+    ) -> IndexerResult<()> {
+        let mut current_u64 = self
+            .client_pool
+            .call(|c| async move { c.block_id_to_u64(&from_block).await })
+            .await?;
+        let to_u64 = self
+            .client_pool
+            .call(|c| async move { c.block_id_to_u64(&to_block).await })
+            .await?;
         let from_u64 = current_u64;
 
         // Some contracts are causing too much recursion for the Cairo VM.
@@ -159,7 +240,11 @@ impl<S: Storage, C: StarknetClient, E: EventHandler + Send + Sync> Sana<S, C, E>
                 break;
             }
 
-            let block_ts = match self.client.block_time(BlockId::Number(current_u64)).await {
+            let block_ts = match self
+                .client_pool
+                .call(|c| async move { c.block_time(BlockId::Number(current_u64)).await })
+                .await
+            {
                 Ok(ts) => ts,
                 Err(e) => {
                     error!(
@@ -198,6 +283,22 @@ impl<S: Storage, C: StarknetClient, E: EventHandler + Send + Sync> Sana<S, C, E>
                 continue;
             }
 
+            let (block_hash, parent_hash) = self
+                .client_pool
+                .call(|c| async move { c.block_hash_and_parent(BlockId::Number(current_u64)).await })
+                .await?;
+
+            if current_u64 > 0 {
+                if let Some(expected_parent_hash) = self.known_block_hash(current_u64 - 1).await? {
+                    if expected_parent_hash != parent_hash {
+                        let ancestor = self.find_common_ancestor(current_u64 - 1).await?;
+                        self.revert_to_ancestor(ancestor, current_u64 - 1).await?;
+                        current_u64 = ancestor + 1;
+                        continue;
+                    }
+                }
+            }
+
             self.event_handler
                 .on_block_processing(block_ts, Some(current_u64))
                 .await;
@@ -213,12 +314,16 @@ impl<S: Storage, C: StarknetClient, E: EventHandler + Send + Sync> Sana<S, C, E>
                 )
                 .await?;
 
+            let keys_selector = self.keys_selector();
             let blocks_events = match self
-                .client
-                .fetch_all_block_events(
-                    BlockId::Number(current_u64),
-                    self.event_manager.keys_selector(),
-                )
+                .client_pool
+                .call(|c| {
+                    let keys_selector = keys_selector.clone();
+                    async move {
+                        c.fetch_all_block_events(BlockId::Number(current_u64), keys_selector)
+                            .await
+                    }
+                })
                 .await
             {
                 Ok(events) => events,
@@ -249,6 +354,11 @@ impl<S: Storage, C: StarknetClient, E: EventHandler + Send + Sync> Sana<S, C, E>
                 )
                 .await?;
 
+            self.block_manager
+                .set_block_hash(current_u64, block_hash, parent_hash)
+                .await?;
+            self.remember_block_hash(current_u64, block_hash).await;
+
             let progress = if to_u64 == from_u64 {
                 if current_u64 == to_u64 {
                     100.0
@@ -271,10 +381,256 @@ impl<S: Storage, C: StarknetClient, E: EventHandler + Send + Sync> Sana<S, C, E>
         Ok(())
     }
 
+    /// Same contract as `index_block_range_sequential`, but network fetch
+    /// for upcoming blocks overlaps with processing of the current one:
+    /// up to `config.concurrency` `fetch_all_block_events` calls (plus
+    /// their timestamp/hash lookups) are in flight at once, while a
+    /// single consumer applies results strictly in ascending block order
+    /// so `set_block_info` and the reorg invariant are preserved.
+    /// `ContractManager`'s write-locked cache remains the only
+    /// serialization point across concurrently fetched blocks.
+    async fn index_block_range_pipelined(
+        &self,
+        from_block: BlockId,
+        to_block: BlockId,
+        force_mode: bool,
+        chain_id: &str,
+    ) -> IndexerResult<()> {
+        let from_u64 = self
+            .client_pool
+            .call(|c| async move { c.block_id_to_u64(&from_block).await })
+            .await?;
+        let to_u64 = self
+            .client_pool
+            .call(|c| async move { c.block_id_to_u64(&to_block).await })
+            .await?;
+
+        let window = self.config.concurrency.max(1);
+
+        // Some contracts are causing too much recursion for the Cairo VM.
+        // This is restarting the full node (Juno) as it is OOM and is shutdown by the OS.
+        // To mitigate this problem before scaling the full node up,
+        // we setup a `max_attempt` to reach the full node before skipping
+        // the entire block.
+        // Currently, we observed that the node almost always reponds after the
+        // second attempt.
+        //
+        // This mirrors `index_block_range_sequential`'s retry/skip handling
+        // so `concurrency > 1` isn't strictly less robust than the
+        // sequential path: a block whose timestamp never shows up is
+        // skipped (not fetched further), and a block-events fetch that
+        // keeps failing is retried indefinitely rather than aborting the
+        // whole range.
+        let max_attempt = 5;
+
+        // Generic over the (unnamed) map type `fetch_all_block_events`
+        // returns, so the consumer can tell a skipped block apart from one
+        // that's actually ready to index.
+        enum BlockFetch<M> {
+            Indexable {
+                number: u64,
+                block_ts: u64,
+                block_hash: FieldElement,
+                parent_hash: FieldElement,
+                blocks_events: M,
+            },
+            Skipped {
+                number: u64,
+            },
+        }
+
+        // Fetches timestamp, hash/parent hash and events for `number` as a
+        // single unit, so the consumer has everything it needs without a
+        // further round trip once the future resolves.
+        let fetch_block = |number: u64| {
+            let this = self;
+            async move {
+                let mut attempt = 0;
+                let block_ts = loop {
+                    match this
+                        .client_pool
+                        .call(|c| async move { c.block_time(BlockId::Number(number)).await })
+                        .await
+                    {
+                        Ok(ts) => break ts,
+                        Err(e) => {
+                            error!(
+                                "Attempt #{} - Couldn't get timestamp for block {}: {:?}",
+                                attempt + 1,
+                                number,
+                                e
+                            );
+                            tokio::time::sleep(Duration::from_secs(1)).await;
+                            attempt += 1;
+
+                            if attempt > max_attempt {
+                                warn!(
+                                    "Skipping block {} as timestamp is not available",
+                                    number
+                                );
+                                return Ok::<_, IndexerError>(BlockFetch::Skipped { number });
+                            }
+                        }
+                    }
+                };
+
+                let (block_hash, parent_hash) = this
+                    .client_pool
+                    .call(|c| async move { c.block_hash_and_parent(BlockId::Number(number)).await })
+                    .await?;
+
+                let keys_selector = this.keys_selector();
+                let blocks_events = loop {
+                    match this
+                        .client_pool
+                        .call(|c| {
+                            let keys_selector = keys_selector.clone();
+                            async move {
+                                c.fetch_all_block_events(BlockId::Number(number), keys_selector)
+                                    .await
+                            }
+                        })
+                        .await
+                    {
+                        Ok(events) => break events,
+                        Err(e) => {
+                            error!("Error while fetching events: {:?}", e);
+                            tokio::time::sleep(Duration::from_secs(1)).await;
+                        }
+                    }
+                };
+
+                Ok::<_, IndexerError>(BlockFetch::Indexable {
+                    number,
+                    block_ts,
+                    block_hash,
+                    parent_hash,
+                    blocks_events,
+                })
+            }
+        };
+
+        let mut next_to_fetch = from_u64;
+        let mut pending = FuturesOrdered::new();
+
+        while next_to_fetch <= to_u64 && pending.len() < window {
+            pending.push_back(fetch_block(next_to_fetch));
+            next_to_fetch += 1;
+        }
+
+        while let Some(result) = pending.next().await {
+            let (number, block_ts, block_hash, parent_hash, blocks_events) = match result? {
+                BlockFetch::Skipped { .. } => {
+                    while next_to_fetch <= to_u64 && pending.len() < window {
+                        pending.push_back(fetch_block(next_to_fetch));
+                        next_to_fetch += 1;
+                    }
+                    continue;
+                }
+                BlockFetch::Indexable {
+                    number,
+                    block_ts,
+                    block_hash,
+                    parent_hash,
+                    blocks_events,
+                } => (number, block_ts, block_hash, parent_hash, blocks_events),
+            };
+
+            if self
+                .block_manager
+                .should_skip_indexing(
+                    number,
+                    block_ts,
+                    self.config.indexer_version.clone(),
+                    force_mode,
+                )
+                .await?
+            {
+                info!("Skipping block {}", number);
+            } else if number > 0
+                && self.known_block_hash(number - 1).await?.is_some_and(|h| h != parent_hash)
+            {
+                let ancestor = self.find_common_ancestor(number - 1).await?;
+                self.revert_to_ancestor(ancestor, number - 1).await?;
+
+                // The in-flight window may contain now-orphaned blocks;
+                // drop it and restart fetching right after the ancestor.
+                pending = FuturesOrdered::new();
+                next_to_fetch = ancestor + 1;
+            } else {
+                self.event_handler
+                    .on_block_processing(block_ts, Some(number))
+                    .await;
+
+                self.block_manager
+                    .set_block_info(
+                        number,
+                        block_ts,
+                        self.config.indexer_version.clone(),
+                        self.config.indexer_identifier.clone(),
+                        BlockIndexingStatus::Processing,
+                    )
+                    .await?;
+
+                let total_events_count: usize =
+                    blocks_events.values().map(|events| events.len()).sum();
+                info!(
+                    "✨ Processing block {}. Total Events Count: {}.",
+                    number, total_events_count
+                );
+
+                for (_, events) in blocks_events {
+                    self.process_events(events, block_ts, chain_id).await?;
+                }
+
+                self.block_manager
+                    .set_block_info(
+                        number,
+                        block_ts,
+                        self.config.indexer_version.clone(),
+                        self.config.indexer_identifier.clone(),
+                        BlockIndexingStatus::Terminated,
+                    )
+                    .await?;
+
+                self.block_manager
+                    .set_block_hash(number, block_hash, parent_hash)
+                    .await?;
+                self.remember_block_hash(number, block_hash).await;
+
+                let progress = if to_u64 == from_u64 {
+                    if number == to_u64 { 100.0 } else { 0.0 }
+                } else {
+                    ((number - from_u64) as f64 / (to_u64 - from_u64) as f64) * 100.0
+                };
+
+                self.event_handler
+                    .on_block_processed(number, progress, force_mode, from_u64, to_u64)
+                    .await;
+            }
+
+            while next_to_fetch <= to_u64 && pending.len() < window {
+                pending.push_back(fetch_block(next_to_fetch));
+                next_to_fetch += 1;
+            }
+        }
+
+        self.event_handler.on_indexation_range_completed().await;
+
+        Ok(())
+    }
+
     pub async fn index_pending_block(&self, timestamp: u64, chain_id: &str) -> IndexerResult<()> {
+        let keys_selector = self.keys_selector();
         let blocks_events = match self
-            .client
-            .fetch_all_block_events_for_pending_block(timestamp, self.event_manager.keys_selector())
+            .client_pool
+            .call(|c| {
+                let keys_selector = keys_selector.clone();
+                async move {
+                    c.fetch_all_block_events_for_pending_block(timestamp, keys_selector)
+                        .await
+                }
+            })
             .await
         {
             Ok(events) => events,
@@ -294,240 +650,125 @@ impl<S: Storage, C: StarknetClient, E: EventHandler + Send + Sync> Sana<S, C, E>
         Ok(())
     }
 
-    async fn process_element_sale(
-        &self,
-        event: EmittedEvent,
-        block_timestamp: u64,
-        chain_id: &str,
-    ) -> Result<()> {
-        trace!("Processing Element sale event...");
-        let mut token_sale_event = self
-            .event_manager
-            .format_element_sale_event(&event, block_timestamp, chain_id)
-            .await?;
-
-        let contract_addr = FieldElement::from_hex_be(
-            token_sale_event.nft_contract_address.as_str(),
-        )
-        .map_err(|e| {
-            error!("Invalid NFT contract address format: {:?}", e);
-            e
-        })?;
-
-        let contract_type = match self
-            .contract_manager
-            .write()
-            .await
-            .identify_contract(contract_addr, block_timestamp, chain_id)
-            .await
-        {
-            Ok(info) => info,
-            Err(e) => {
-                error!(
-                    "Error while identifying contract {}: {:?}",
-                    token_sale_event.nft_contract_address, e
-                );
-                return Ok(());
-            }
-        };
-
-        if contract_type != ContractType::ERC721 {
-            debug!(
-                "Contract is not an ERC271 NFT: {}",
-                token_sale_event.nft_contract_address
-            );
-            return Ok(());
+    /// Records `(number, hash)` in the in-memory recency window, evicting
+    /// the oldest entry once it's full.
+    async fn remember_block_hash(&self, number: u64, hash: FieldElement) {
+        let mut recent = self.recent_block_hashes.lock().await;
+        recent.push_back((number, hash));
+        if recent.len() > RECENT_BLOCK_HASHES_CAPACITY {
+            recent.pop_front();
         }
-
-        token_sale_event.nft_type = Some(contract_type.to_string());
-        self.event_manager
-            .register_sale_event(&token_sale_event, block_timestamp)
-            .await?;
-
-        Ok(())
     }
 
-    async fn process_ventory_sale(
-        &self,
-        event: EmittedEvent,
-        block_timestamp: u64,
-        chain_id: &str,
-    ) -> Result<()> {
-        trace!("Processing Ventory sale event...");
-
-        let mut token_sale_event = self
-            .event_manager
-            .format_ventory_sale_event(&event, block_timestamp)
-            .await?;
-
-        let contract_addr = FieldElement::from_hex_be(
-            token_sale_event.nft_contract_address.as_str(),
-        )
-        .map_err(|e| {
-            error!("Invalid NFT contract address format: {:?}", e);
-            e
-        })?;
-
-        let contract_type = match self
-            .contract_manager
-            .write()
-            .await
-            .identify_contract(contract_addr, block_timestamp, chain_id)
+    /// Returns the hash we recorded for `number`, checking the in-memory
+    /// window first and falling back to the persisted value on a cold
+    /// lookup (e.g. right after a restart, or once the window has
+    /// scrolled past `number`).
+    async fn known_block_hash(&self, number: u64) -> IndexerResult<Option<FieldElement>> {
+        if let Some((_, hash)) = self
+            .recent_block_hashes
+            .lock()
             .await
+            .iter()
+            .rev()
+            .find(|(n, _)| *n == number)
         {
-            Ok(info) => info,
-            Err(e) => {
-                error!(
-                    "Error while identifying contract {}: {:?}",
-                    token_sale_event.nft_contract_address, e
-                );
-                return Ok(());
-            }
-        };
-
-        if contract_type != ContractType::ERC721 {
-            debug!(
-                "Contract is not an ERC271 NFT: {}",
-                token_sale_event.nft_contract_address
-            );
-            return Ok(());
+            return Ok(Some(*hash));
         }
 
-        token_sale_event.nft_type = Some(contract_type.to_string());
-        self.event_manager
-            .register_sale_event(&token_sale_event, block_timestamp)
-            .await?;
-
-        Ok(())
+        Ok(self.block_manager.block_hash(number).await?)
     }
 
-    async fn process_marketplace_event(
-        &self,
-        event: EmittedEvent,
-        block_timestamp: u64,
-        chain_id: &str,
-    ) -> Result<()> {
-        let element_sale_event_name = FieldElement::from_hex_be(ELEMENT_MARKETPLACE_EVENT_HEX)?;
-        let ventory_sale_event_name = FieldElement::from_hex_be(VENTORY_MARKETPLACE_EVENT_HEX)?;
-
-        if let Some(event_name) = event.keys.first() {
-            info!("Processing marketplace event: {:?}", event_name);
+    /// Starting from `suspect_block` (the block whose stored hash didn't
+    /// match the parent hash of the block after it), walks backwards comparing
+    /// the chain's reported parent hash against our stored hash for each
+    /// earlier block until the two agree. Returns the last block both
+    /// versions of the chain agree on.
+    async fn find_common_ancestor(&self, suspect_block: u64) -> IndexerResult<u64> {
+        let mut block = suspect_block;
+
+        while block > 0 {
+            let (_, parent_hash) = self
+                .client_pool
+                .call(|c| async move { c.block_hash_and_parent(BlockId::Number(block)).await })
+                .await?;
 
-            match event_name {
-                name if name == &element_sale_event_name => {
-                    self.process_element_sale(event, block_timestamp, chain_id)
-                        .await?
-                }
-                name if name == &ventory_sale_event_name => {
-                    self.process_ventory_sale(event, block_timestamp, chain_id)
-                        .await?
-                }
-                _ => {
-                    warn!("Unknown marketplace event: {:?}", event.keys);
+            if let Some(stored_hash) = self.known_block_hash(block - 1).await? {
+                if stored_hash == parent_hash {
+                    return Ok(block - 1);
                 }
             }
+
+            block -= 1;
         }
 
-        Ok(())
+        Ok(0)
     }
 
-    async fn process_nft_transfers(
-        &self,
-        event: EmittedEvent,
-        block_timestamp: u64,
-        contract_address: FieldElement,
-        chain_id: &str,
-    ) -> Result<()> {
-        let contract_address_hex = to_hex_str(&contract_address);
-        let contract_type = self
-            .contract_manager
-            .write()
-            .await
-            .identify_contract(contract_address, block_timestamp, chain_id)
-            .await
-            .map_err(|e| {
-                error!(
-                    "Error while identifying contract {}: {:?}",
-                    contract_address_hex, e
-                );
-                e
-            })?;
-
-        if contract_type != ContractType::ERC721 {
-            debug!("Contract is not an ERC271 NFT: {}", contract_address_hex);
-            return Ok(());
-        }
-
-        info!(
-            "Processing event... Block Id: {:?}, Tx Hash: 0x{:064x}, contract_type: {:?}",
-            event.block_number, event.transaction_hash, contract_type
+    /// Reverts every block from `ancestor` (exclusive) up to
+    /// `reorg_block` (inclusive): deletes their recorded token events,
+    /// sales and balance deltas, resets their indexing status, and drops
+    /// them from the in-memory recency window. The invariant this
+    /// restores is that no block stays marked `Terminated` unless its
+    /// parent hash links to the stored chain, so indexing can safely
+    /// resume right after `ancestor`.
+    async fn revert_to_ancestor(&self, ancestor: u64, reorg_block: u64) -> IndexerResult<()> {
+        warn!(
+            "Reorg detected: reverting blocks {}..={} back to common ancestor {}",
+            ancestor + 1,
+            reorg_block,
+            ancestor
         );
 
-        let (token_id, token_event) = self
-            .event_manager
-            .extract_data_event(&event, contract_type, block_timestamp)
-            .await
-            .map_err(|err| {
-                error!("Error while registering event {:?}\n{:?}", err, event);
-                err
-            })?;
-
-        self.token_manager
-            .format_and_register_token(&token_id, &token_event, block_timestamp, event.block_number)
-            .await
-            .map_err(|err| {
-                error!("Can't format token {:?}\n event: {:?}", err, token_event);
-                err
-            })?;
+        self.block_manager
+            .on_blocks_reverted(ancestor + 1, reorg_block)
+            .await?;
+        self.event_handler
+            .on_blocks_reverted(ancestor + 1, reorg_block)
+            .await;
 
-        self.event_manager
-            .format_and_register_event(token_event)
+        self.recent_block_hashes
+            .lock()
             .await
-            .map_err(|err| {
-                error!("Error while registering event {:?}\n{:?}", err, event);
-                err
-            })?;
+            .retain(|(n, _)| *n <= ancestor);
 
         Ok(())
     }
 
-    /// Inner function to process events.
+    /// Dispatches every event of a block to whichever registered
+    /// processors claim it via `validate`. Each processor's error is
+    /// logged and isolated so one misbehaving processor can't stop the
+    /// others from running on the same event.
     async fn process_events(
         &self,
         events: Vec<EmittedEvent>,
         block_timestamp: u64,
         chain_id: &str,
     ) -> IndexerResult<()> {
-        let marketplace_contracts = [
-            FieldElement::from_hex_be(
-                "0x04d8bb956e6bd7a50fcb8b49d8e9fd8269cfadbeb73f457fd6d3fc1dff4b879e", // Element Marketplace
-            )
-            .unwrap(),
-            FieldElement::from_hex_be(
-                "0x008755a98ccf7d25e69aa90ef3b73b07c470ba4ec6391b0b0c7c598f992c3fee", // Ventory Marketplace
-            )
-            .unwrap(),
-        ];
+        let ctx = ProcessorContext {
+            block_manager: &self.block_manager,
+            event_manager: &self.event_manager,
+            token_manager: &self.token_manager,
+            contract_manager: &self.contract_manager,
+        };
 
         for e in events {
-            let contract_address = e.from_address;
-            let is_marketplace_event = marketplace_contracts.contains(&contract_address);
+            for processor in &self.processors {
+                if !processor.validate(&e) {
+                    continue;
+                }
 
-            if is_marketplace_event {
-                if let Err(err) = self
-                    .process_marketplace_event(e.clone(), block_timestamp, chain_id)
+                if let Err(err) = processor
+                    .process(e.clone(), block_timestamp, chain_id, &ctx)
                     .await
                 {
-                    error!("Error while processing marketplace event: {:?}", err);
+                    error!(
+                        "Error while processing event with {}: {:?}",
+                        processor.name(),
+                        err
+                    );
                 }
             }
-
-            if let Err(e) = self
-                .process_nft_transfers(e.clone(), block_timestamp, contract_address, chain_id)
-                .await
-            {
-                error!("Error while processing NFT transfers: {:?}", e);
-            }
         }
 
         Ok(())
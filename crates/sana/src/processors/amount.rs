@@ -0,0 +1,17 @@
+use anyhow::{anyhow, Result};
+use starknet::core::types::FieldElement;
+
+/// Splits a Cairo `u256` encoded as `[low, high]` felts into its two limbs.
+/// Starknet ABIs represent a `u256` as two consecutive values (a felt can't
+/// hold a full 256-bit integer), and every storage row for a fungible
+/// transfer keeps that same low/high split rather than trying to collapse
+/// it into a single value.
+pub(super) fn decode_u256(data: &[FieldElement]) -> Result<(FieldElement, FieldElement)> {
+    let low = *data
+        .first()
+        .ok_or_else(|| anyhow!("missing u256 low limb in event data"))?;
+    let high = *data
+        .get(1)
+        .ok_or_else(|| anyhow!("missing u256 high limb in event data"))?;
+    Ok((low, high))
+}
@@ -0,0 +1,103 @@
+use super::{EventProcessor, ProcessorContext};
+use crate::storage::types::ContractType;
+use crate::storage::Storage;
+use anyhow::Result;
+use ark_starknet::client::StarknetClient;
+use async_trait::async_trait;
+use starknet::core::types::*;
+use tracing::{debug, error, trace};
+
+const ELEMENT_MARKETPLACE_EVENT_HEX: &str =
+    "0x351e5a57ea6ca22e3e3cd212680ef7f3b57404609bda942a5e75ba4724b55e0";
+
+const ELEMENT_MARKETPLACE_CONTRACT_HEX: &str =
+    "0x04d8bb956e6bd7a50fcb8b49d8e9fd8269cfadbeb73f457fd6d3fc1dff4b879e";
+
+/// Handles `EventListingBought`-style sale events emitted by the Element
+/// marketplace contract.
+pub struct ElementSaleProcessor {
+    event_name: FieldElement,
+    marketplace_contract: FieldElement,
+}
+
+impl ElementSaleProcessor {
+    pub fn new() -> Self {
+        Self {
+            event_name: FieldElement::from_hex_be(ELEMENT_MARKETPLACE_EVENT_HEX)
+                .expect("invalid Element marketplace event selector"),
+            marketplace_contract: FieldElement::from_hex_be(ELEMENT_MARKETPLACE_CONTRACT_HEX)
+                .expect("invalid Element marketplace contract address"),
+        }
+    }
+}
+
+#[async_trait]
+impl<S: Storage, C: StarknetClient> EventProcessor<S, C> for ElementSaleProcessor {
+    fn name(&self) -> &'static str {
+        "element_sale"
+    }
+
+    fn validate(&self, event: &EmittedEvent) -> bool {
+        event.from_address == self.marketplace_contract
+            && event.keys.first() == Some(&self.event_name)
+    }
+
+    fn event_keys(&self) -> Vec<FieldElement> {
+        vec![self.event_name]
+    }
+
+    async fn process(
+        &self,
+        event: EmittedEvent,
+        block_timestamp: u64,
+        chain_id: &str,
+        ctx: &ProcessorContext<'_, S, C>,
+    ) -> Result<()> {
+        trace!("Processing Element sale event...");
+
+        let mut token_sale_event = ctx
+            .event_manager
+            .format_element_sale_event(&event, block_timestamp, chain_id)
+            .await?;
+
+        let contract_addr = FieldElement::from_hex_be(
+            token_sale_event.nft_contract_address.as_str(),
+        )
+        .map_err(|e| {
+            error!("Invalid NFT contract address format: {:?}", e);
+            e
+        })?;
+
+        let contract_type = match ctx
+            .contract_manager
+            .write()
+            .await
+            .identify_contract(contract_addr, block_timestamp, chain_id)
+            .await
+        {
+            Ok(info) => info,
+            Err(e) => {
+                error!(
+                    "Error while identifying contract {}: {:?}",
+                    token_sale_event.nft_contract_address, e
+                );
+                return Ok(());
+            }
+        };
+
+        if contract_type != ContractType::ERC721 {
+            debug!(
+                "Contract is not an ERC271 NFT: {}",
+                token_sale_event.nft_contract_address
+            );
+            return Ok(());
+        }
+
+        token_sale_event.nft_type = Some(contract_type.to_string());
+        ctx.event_manager
+            .register_sale_event(&token_sale_event, block_timestamp)
+            .await?;
+
+        Ok(())
+    }
+}
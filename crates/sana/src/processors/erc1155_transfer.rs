@@ -0,0 +1,222 @@
+use super::amount::decode_u256;
+use super::{EventProcessor, ProcessorContext};
+use crate::storage::types::ContractType;
+use crate::storage::Storage;
+use anyhow::{anyhow, Result};
+use ark_starknet::client::StarknetClient;
+use ark_starknet::format::to_hex_str;
+use async_trait::async_trait;
+use starknet::core::types::*;
+use starknet::core::utils::get_selector_from_name;
+use tracing::{debug, error, trace};
+
+/// Handles ERC-1155 `TransferSingle(operator, from, to, id, value)` and
+/// `TransferBatch(operator, from, to, ids[], values[])` events, registering
+/// one balance update per `(id, value)` pair so a batch transfer produces
+/// the same storage effect as the equivalent sequence of single transfers.
+///
+/// `operator`, `from` and `to` are all `#[key]` fields on the Cairo
+/// `TransferSingle`/`TransferBatch` events, so `event.keys` is
+/// `[event_name, operator, from, to]`; only `id`/`value` (or the `ids[]`/
+/// `values[]` arrays) live in `data`. Balances are tracked per
+/// `(contract, account)` rather than per `(contract, account, id)`, so a
+/// batch transfer only needs the `values[]` array — the `ids[]` array is
+/// parsed solely to find where `values[]` starts.
+pub struct Erc1155TransferProcessor {
+    transfer_single_event: FieldElement,
+    transfer_batch_event: FieldElement,
+}
+
+impl Erc1155TransferProcessor {
+    pub fn new() -> Self {
+        Self {
+            transfer_single_event: get_selector_from_name("TransferSingle")
+                .expect("invalid TransferSingle event selector"),
+            transfer_batch_event: get_selector_from_name("TransferBatch")
+                .expect("invalid TransferBatch event selector"),
+        }
+    }
+
+    async fn process_single<S: Storage, C: StarknetClient>(
+        &self,
+        event: &EmittedEvent,
+        contract_address: FieldElement,
+        block_timestamp: u64,
+        ctx: &ProcessorContext<'_, S, C>,
+    ) -> Result<()> {
+        // Keys: [event_name, operator, from, to]; `id`/`value` stay in `data`.
+        let from = *event
+            .keys
+            .get(2)
+            .ok_or_else(|| anyhow!("TransferSingle event is missing the `from` key"))?;
+        let to = *event
+            .keys
+            .get(3)
+            .ok_or_else(|| anyhow!("TransferSingle event is missing the `to` key"))?;
+        let (id_low, id_high) = decode_u256(&event.data)?;
+        let value_data = event
+            .data
+            .get(2..)
+            .ok_or_else(|| anyhow!("TransferSingle event is missing the `value` limbs"))?;
+        let (amount_low, amount_high) = decode_u256(value_data)?;
+
+        trace!(
+            "Processing ERC-1155 TransferSingle on {}: {:#x} -> {:#x}, id {:#x}{:#x}",
+            to_hex_str(&contract_address),
+            from,
+            to,
+            id_high,
+            id_low
+        );
+
+        ctx.token_manager
+            .register_fungible_transfer(
+                contract_address,
+                from,
+                to,
+                amount_low,
+                amount_high,
+                block_timestamp,
+                event.block_number,
+                event.transaction_hash,
+            )
+            .await
+            .map_err(|err| {
+                error!(
+                    "Can't register ERC-1155 TransferSingle {:?}\nevent: {:?}",
+                    err, event
+                );
+                err.into()
+            })
+    }
+
+    async fn process_batch<S: Storage, C: StarknetClient>(
+        &self,
+        event: &EmittedEvent,
+        contract_address: FieldElement,
+        block_timestamp: u64,
+        ctx: &ProcessorContext<'_, S, C>,
+    ) -> Result<()> {
+        // Keys: [event_name, operator, from, to]; the ids/values arrays stay in `data`.
+        let from = *event
+            .keys
+            .get(2)
+            .ok_or_else(|| anyhow!("TransferBatch event is missing the `from` key"))?;
+        let to = *event
+            .keys
+            .get(3)
+            .ok_or_else(|| anyhow!("TransferBatch event is missing the `to` key"))?;
+
+        // `data` is laid out as `[ids_len, ids..., values_len, values...]`,
+        // each id/value itself encoded as a `u256` (two felts).
+        let ids_len = event
+            .data
+            .first()
+            .ok_or_else(|| anyhow!("TransferBatch event is missing the ids array length"))?
+            .to_string()
+            .parse::<usize>()
+            .map_err(|e| anyhow!("invalid ids array length: {e}"))?;
+
+        // Computed with checked arithmetic: `ids_len` comes straight off the
+        // chain, and an attacker-controlled length large enough to overflow
+        // `usize` must be rejected rather than wrap into a short, in-bounds
+        // (and wrong) slice.
+        let overflow_err = || anyhow!("TransferBatch ids array length {} overflows", ids_len);
+        let ids_felts = ids_len.checked_mul(2).ok_or_else(overflow_err)?;
+        let ids_end = ids_felts.checked_add(1).ok_or_else(overflow_err)?;
+        let values_start = ids_end.checked_add(1).ok_or_else(overflow_err)?;
+        let values_end = values_start.checked_add(ids_felts).ok_or_else(overflow_err)?;
+
+        if event.data.len() < values_end {
+            return Err(anyhow!(
+                "TransferBatch event data is too short for {} ids (got {} felts, need {})",
+                ids_len,
+                event.data.len(),
+                values_end
+            ));
+        }
+
+        for i in 0..ids_len {
+            let (amount_low, amount_high) =
+                decode_u256(&event.data[values_start + i * 2..])?;
+
+            ctx.token_manager
+                .register_fungible_transfer(
+                    contract_address,
+                    from,
+                    to,
+                    amount_low,
+                    amount_high,
+                    block_timestamp,
+                    event.block_number,
+                    event.transaction_hash,
+                )
+                .await
+                .map_err(|err| {
+                    error!(
+                        "Can't register ERC-1155 TransferBatch entry {:?}\nevent: {:?}",
+                        err, event
+                    );
+                    err
+                })?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<S: Storage, C: StarknetClient> EventProcessor<S, C> for Erc1155TransferProcessor {
+    fn name(&self) -> &'static str {
+        "erc1155_transfer"
+    }
+
+    fn validate(&self, event: &EmittedEvent) -> bool {
+        matches!(
+            event.keys.first(),
+            Some(key) if key == &self.transfer_single_event || key == &self.transfer_batch_event
+        )
+    }
+
+    fn event_keys(&self) -> Vec<FieldElement> {
+        vec![self.transfer_single_event, self.transfer_batch_event]
+    }
+
+    async fn process(
+        &self,
+        event: EmittedEvent,
+        block_timestamp: u64,
+        chain_id: &str,
+        ctx: &ProcessorContext<'_, S, C>,
+    ) -> Result<()> {
+        let contract_address = event.from_address;
+        let contract_address_hex = to_hex_str(&contract_address);
+
+        let contract_type = ctx
+            .contract_manager
+            .write()
+            .await
+            .identify_contract(contract_address, block_timestamp, chain_id)
+            .await
+            .map_err(|e| {
+                error!(
+                    "Error while identifying contract {}: {:?}",
+                    contract_address_hex, e
+                );
+                e
+            })?;
+
+        if contract_type != ContractType::ERC1155 {
+            debug!("Contract is not an ERC1155 token: {}", contract_address_hex);
+            return Ok(());
+        }
+
+        if event.keys.first() == Some(&self.transfer_single_event) {
+            self.process_single(&event, contract_address, block_timestamp, ctx)
+                .await
+        } else {
+            self.process_batch(&event, contract_address, block_timestamp, ctx)
+                .await
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use super::amount::decode_u256;
+use super::{EventProcessor, ProcessorContext};
+use crate::storage::types::ContractType;
+use crate::storage::Storage;
+use anyhow::{anyhow, Result};
+use ark_starknet::client::StarknetClient;
+use ark_starknet::format::to_hex_str;
+use async_trait::async_trait;
+use starknet::core::types::*;
+use starknet::core::utils::get_selector_from_name;
+use tracing::{debug, error, trace};
+
+/// Handles modern ERC-20 `Transfer(from, to, value)` events where `from`
+/// and `to` are indexed, i.e. part of `event.keys` rather than
+/// `event.data`. Older tokens that pack both addresses into `data`
+/// instead are handled by [`super::erc20_transfer_legacy::Erc20TransferLegacyProcessor`].
+pub struct Erc20TransferProcessor {
+    transfer_event: FieldElement,
+}
+
+impl Erc20TransferProcessor {
+    pub fn new() -> Self {
+        Self {
+            transfer_event: get_selector_from_name("Transfer")
+                .expect("invalid Transfer event selector"),
+        }
+    }
+}
+
+#[async_trait]
+impl<S: Storage, C: StarknetClient> EventProcessor<S, C> for Erc20TransferProcessor {
+    fn name(&self) -> &'static str {
+        "erc20_transfer"
+    }
+
+    fn validate(&self, event: &EmittedEvent) -> bool {
+        event.keys.first() == Some(&self.transfer_event) && event.keys.len() >= 3
+    }
+
+    fn event_keys(&self) -> Vec<FieldElement> {
+        vec![self.transfer_event]
+    }
+
+    async fn process(
+        &self,
+        event: EmittedEvent,
+        block_timestamp: u64,
+        chain_id: &str,
+        ctx: &ProcessorContext<'_, S, C>,
+    ) -> Result<()> {
+        let contract_address = event.from_address;
+        let contract_address_hex = to_hex_str(&contract_address);
+
+        let contract_type = ctx
+            .contract_manager
+            .write()
+            .await
+            .identify_contract(contract_address, block_timestamp, chain_id)
+            .await
+            .map_err(|e| {
+                error!(
+                    "Error while identifying contract {}: {:?}",
+                    contract_address_hex, e
+                );
+                e
+            })?;
+
+        if contract_type != ContractType::ERC20 {
+            debug!("Contract is not an ERC20 token: {}", contract_address_hex);
+            return Ok(());
+        }
+
+        let from = *event
+            .keys
+            .get(1)
+            .ok_or_else(|| anyhow!("Transfer event is missing the `from` key"))?;
+        let to = *event
+            .keys
+            .get(2)
+            .ok_or_else(|| anyhow!("Transfer event is missing the `to` key"))?;
+        let (amount_low, amount_high) = decode_u256(&event.data)?;
+
+        trace!(
+            "Processing ERC-20 transfer on {}: {:#x} -> {:#x}",
+            contract_address_hex,
+            from,
+            to
+        );
+
+        ctx.token_manager
+            .register_fungible_transfer(
+                contract_address,
+                from,
+                to,
+                amount_low,
+                amount_high,
+                block_timestamp,
+                event.block_number,
+                event.transaction_hash,
+            )
+            .await
+            .map_err(|err| {
+                error!(
+                    "Can't register ERC-20 transfer {:?}\nevent: {:?}",
+                    err, event
+                );
+                err
+            })?;
+
+        Ok(())
+    }
+}
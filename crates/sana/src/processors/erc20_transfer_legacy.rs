@@ -0,0 +1,118 @@
+use super::amount::decode_u256;
+use super::{EventProcessor, ProcessorContext};
+use crate::storage::types::ContractType;
+use crate::storage::Storage;
+use anyhow::{anyhow, Result};
+use ark_starknet::client::StarknetClient;
+use ark_starknet::format::to_hex_str;
+use async_trait::async_trait;
+use starknet::core::types::*;
+use starknet::core::utils::get_selector_from_name;
+use tracing::{debug, error, trace};
+
+/// Handles legacy ERC-20 `Transfer` events where `from`/`to` were never
+/// indexed and instead sit in `event.data` as `[from, to, value_low,
+/// value_high]`. Several early Starknet tokens still emit this shape, so
+/// it gets its own processor rather than branching inside
+/// [`super::erc20_transfer::Erc20TransferProcessor`].
+pub struct Erc20TransferLegacyProcessor {
+    transfer_event: FieldElement,
+}
+
+impl Erc20TransferLegacyProcessor {
+    pub fn new() -> Self {
+        Self {
+            transfer_event: get_selector_from_name("Transfer")
+                .expect("invalid Transfer event selector"),
+        }
+    }
+}
+
+#[async_trait]
+impl<S: Storage, C: StarknetClient> EventProcessor<S, C> for Erc20TransferLegacyProcessor {
+    fn name(&self) -> &'static str {
+        "erc20_transfer_legacy"
+    }
+
+    fn validate(&self, event: &EmittedEvent) -> bool {
+        // Only the event name is indexed; `from`/`to` live in `data`.
+        event.keys.first() == Some(&self.transfer_event) && event.keys.len() == 1
+    }
+
+    fn event_keys(&self) -> Vec<FieldElement> {
+        vec![self.transfer_event]
+    }
+
+    async fn process(
+        &self,
+        event: EmittedEvent,
+        block_timestamp: u64,
+        chain_id: &str,
+        ctx: &ProcessorContext<'_, S, C>,
+    ) -> Result<()> {
+        let contract_address = event.from_address;
+        let contract_address_hex = to_hex_str(&contract_address);
+
+        let contract_type = ctx
+            .contract_manager
+            .write()
+            .await
+            .identify_contract(contract_address, block_timestamp, chain_id)
+            .await
+            .map_err(|e| {
+                error!(
+                    "Error while identifying contract {}: {:?}",
+                    contract_address_hex, e
+                );
+                e
+            })?;
+
+        if contract_type != ContractType::ERC20 {
+            debug!("Contract is not an ERC20 token: {}", contract_address_hex);
+            return Ok(());
+        }
+
+        let from = *event
+            .data
+            .first()
+            .ok_or_else(|| anyhow!("legacy Transfer event is missing `from` in data"))?;
+        let to = *event
+            .data
+            .get(1)
+            .ok_or_else(|| anyhow!("legacy Transfer event is missing `to` in data"))?;
+        let value_data = event
+            .data
+            .get(2..)
+            .ok_or_else(|| anyhow!("legacy Transfer event is missing the `value` limbs"))?;
+        let (amount_low, amount_high) = decode_u256(value_data)?;
+
+        trace!(
+            "Processing legacy ERC-20 transfer on {}: {:#x} -> {:#x}",
+            contract_address_hex,
+            from,
+            to
+        );
+
+        ctx.token_manager
+            .register_fungible_transfer(
+                contract_address,
+                from,
+                to,
+                amount_low,
+                amount_high,
+                block_timestamp,
+                event.block_number,
+                event.transaction_hash,
+            )
+            .await
+            .map_err(|err| {
+                error!(
+                    "Can't register legacy ERC-20 transfer {:?}\nevent: {:?}",
+                    err, event
+                );
+                err
+            })?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use super::{EventProcessor, ProcessorContext};
+use crate::storage::types::ContractType;
+use crate::storage::Storage;
+use anyhow::Result;
+use ark_starknet::client::StarknetClient;
+use ark_starknet::format::to_hex_str;
+use async_trait::async_trait;
+use starknet::core::types::*;
+use starknet::core::utils::get_selector_from_name;
+use tracing::{debug, error, info, trace};
+
+/// Handles ERC-721 `Transfer` events: mints, transfers and burns that feed
+/// `TokenManager`/`EventManager`. Unlike the marketplace sale processors,
+/// this one can't tell from the event keys alone whether a contract is an
+/// ERC-721, so it accepts every event and relies on `identify_contract` to
+/// filter.
+pub struct Erc721TransferProcessor {
+    transfer_event: FieldElement,
+}
+
+impl Erc721TransferProcessor {
+    pub fn new() -> Self {
+        Self {
+            transfer_event: get_selector_from_name("Transfer")
+                .expect("invalid Transfer event selector"),
+        }
+    }
+}
+
+#[async_trait]
+impl<S: Storage, C: StarknetClient> EventProcessor<S, C> for Erc721TransferProcessor {
+    fn name(&self) -> &'static str {
+        "erc721_transfer"
+    }
+
+    fn validate(&self, _event: &EmittedEvent) -> bool {
+        true
+    }
+
+    fn event_keys(&self) -> Vec<FieldElement> {
+        vec![self.transfer_event]
+    }
+
+    async fn process(
+        &self,
+        event: EmittedEvent,
+        block_timestamp: u64,
+        chain_id: &str,
+        ctx: &ProcessorContext<'_, S, C>,
+    ) -> Result<()> {
+        let contract_address = event.from_address;
+        let contract_address_hex = to_hex_str(&contract_address);
+
+        let contract_type = ctx
+            .contract_manager
+            .write()
+            .await
+            .identify_contract(contract_address, block_timestamp, chain_id)
+            .await
+            .map_err(|e| {
+                error!(
+                    "Error while identifying contract {}: {:?}",
+                    contract_address_hex, e
+                );
+                e
+            })?;
+
+        if contract_type != ContractType::ERC721 {
+            debug!("Contract is not an ERC271 NFT: {}", contract_address_hex);
+            return Ok(());
+        }
+
+        info!(
+            "Processing event... Block Id: {:?}, Tx Hash: 0x{:064x}, contract_type: {:?}",
+            event.block_number, event.transaction_hash, contract_type
+        );
+
+        let (token_id, token_event) = ctx
+            .event_manager
+            .extract_data_event(&event, contract_type, block_timestamp)
+            .await
+            .map_err(|err| {
+                error!("Error while registering event {:?}\n{:?}", err, event);
+                err
+            })?;
+
+        ctx.token_manager
+            .format_and_register_token(&token_id, &token_event, block_timestamp, event.block_number)
+            .await
+            .map_err(|err| {
+                error!("Can't format token {:?}\n event: {:?}", err, token_event);
+                err
+            })?;
+
+        ctx.event_manager
+            .format_and_register_event(token_event)
+            .await
+            .map_err(|err| {
+                error!("Error while registering event {:?}\n{:?}", err, event);
+                err
+            })?;
+
+        trace!("Registered ERC-721 transfer for {}", contract_address_hex);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+//! Registry of [`EventProcessor`] implementations.
+//!
+//! Each marketplace or token standard Sana understands is implemented as a
+//! small, self-contained [`EventProcessor`]. `Sana::new` builds the list of
+//! processors once and `process_events` dispatches every emitted event to
+//! whichever processors claim it, instead of hardcoding a contract/selector
+//! switch in the core loop.
+
+mod amount;
+mod element_sale;
+mod erc1155_transfer;
+mod erc20_transfer;
+mod erc20_transfer_legacy;
+mod erc721_transfer;
+mod ventory_sale;
+
+pub use element_sale::ElementSaleProcessor;
+pub use erc1155_transfer::Erc1155TransferProcessor;
+pub use erc20_transfer::Erc20TransferProcessor;
+pub use erc20_transfer_legacy::Erc20TransferLegacyProcessor;
+pub use erc721_transfer::Erc721TransferProcessor;
+pub use ventory_sale::VentorySaleProcessor;
+
+use crate::managers::{BlockManager, ContractManager, EventManager, TokenManager};
+use crate::storage::Storage;
+use anyhow::Result;
+use ark_starknet::client::StarknetClient;
+use async_trait::async_trait;
+use starknet::core::types::*;
+use std::sync::Arc;
+use tokio::sync::RwLock as AsyncRwLock;
+
+/// The set of managers a processor needs to resolve contracts and persist
+/// whatever it extracts from an event. Bundled here so `EventProcessor`
+/// implementations don't each need their own copy of `Sana`'s fields.
+pub struct ProcessorContext<'a, S: Storage, C: StarknetClient> {
+    pub block_manager: &'a Arc<BlockManager<S>>,
+    pub event_manager: &'a Arc<EventManager<S>>,
+    pub token_manager: &'a Arc<TokenManager<S, C>>,
+    pub contract_manager: &'a Arc<AsyncRwLock<ContractManager<S, C>>>,
+}
+
+/// A unit of event handling that Sana can register at startup.
+///
+/// Implementations own the knowledge of which event(s) they care about and
+/// how to turn them into storage writes; the core indexing loop only knows
+/// how to call `validate`/`process` and how to collect `event_keys`.
+#[async_trait]
+pub trait EventProcessor<S: Storage, C: StarknetClient>: Send + Sync {
+    /// Short identifier used in logs.
+    fn name(&self) -> &'static str;
+
+    /// Returns `true` if this processor handles the given event.
+    fn validate(&self, event: &EmittedEvent) -> bool;
+
+    /// Event key selectors this processor wants included in the combined
+    /// selector set passed to `fetch_all_block_events`.
+    fn event_keys(&self) -> Vec<FieldElement>;
+
+    /// Processes a single event that already passed `validate`.
+    async fn process(
+        &self,
+        event: EmittedEvent,
+        block_timestamp: u64,
+        chain_id: &str,
+        ctx: &ProcessorContext<'_, S, C>,
+    ) -> Result<()>;
+}
+
+/// Builds the default set of processors Sana ships with: the Element and
+/// Ventory marketplace sale processors, the generic ERC-721 transfer
+/// processor, and the ERC-20/ERC-1155 fungible and semi-fungible transfer
+/// processors (including the legacy, non-indexed ERC-20 `Transfer` shape).
+pub fn default_processors<S: Storage, C: StarknetClient>() -> Vec<Box<dyn EventProcessor<S, C>>> {
+    vec![
+        Box::new(ElementSaleProcessor::new()),
+        Box::new(VentorySaleProcessor::new()),
+        Box::new(Erc721TransferProcessor::new()),
+        Box::new(Erc20TransferProcessor::new()),
+        Box::new(Erc20TransferLegacyProcessor::new()),
+        Box::new(Erc1155TransferProcessor::new()),
+    ]
+}
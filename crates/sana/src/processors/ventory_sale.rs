@@ -0,0 +1,103 @@
+use super::{EventProcessor, ProcessorContext};
+use crate::storage::types::ContractType;
+use crate::storage::Storage;
+use anyhow::Result;
+use ark_starknet::client::StarknetClient;
+use async_trait::async_trait;
+use starknet::core::types::*;
+use tracing::{debug, error, trace};
+
+const VENTORY_MARKETPLACE_EVENT_HEX: &str =
+    "0x1b43f40d55364e989b3a8674460f61ba8f327542298ee6240a54ee2bf7b55bb"; // EventListingBought
+
+const VENTORY_MARKETPLACE_CONTRACT_HEX: &str =
+    "0x008755a98ccf7d25e69aa90ef3b73b07c470ba4ec6391b0b0c7c598f992c3fee";
+
+/// Handles `EventListingBought` sale events emitted by the Ventory
+/// marketplace contract.
+pub struct VentorySaleProcessor {
+    event_name: FieldElement,
+    marketplace_contract: FieldElement,
+}
+
+impl VentorySaleProcessor {
+    pub fn new() -> Self {
+        Self {
+            event_name: FieldElement::from_hex_be(VENTORY_MARKETPLACE_EVENT_HEX)
+                .expect("invalid Ventory marketplace event selector"),
+            marketplace_contract: FieldElement::from_hex_be(VENTORY_MARKETPLACE_CONTRACT_HEX)
+                .expect("invalid Ventory marketplace contract address"),
+        }
+    }
+}
+
+#[async_trait]
+impl<S: Storage, C: StarknetClient> EventProcessor<S, C> for VentorySaleProcessor {
+    fn name(&self) -> &'static str {
+        "ventory_sale"
+    }
+
+    fn validate(&self, event: &EmittedEvent) -> bool {
+        event.from_address == self.marketplace_contract
+            && event.keys.first() == Some(&self.event_name)
+    }
+
+    fn event_keys(&self) -> Vec<FieldElement> {
+        vec![self.event_name]
+    }
+
+    async fn process(
+        &self,
+        event: EmittedEvent,
+        block_timestamp: u64,
+        chain_id: &str,
+        ctx: &ProcessorContext<'_, S, C>,
+    ) -> Result<()> {
+        trace!("Processing Ventory sale event...");
+
+        let mut token_sale_event = ctx
+            .event_manager
+            .format_ventory_sale_event(&event, block_timestamp)
+            .await?;
+
+        let contract_addr = FieldElement::from_hex_be(
+            token_sale_event.nft_contract_address.as_str(),
+        )
+        .map_err(|e| {
+            error!("Invalid NFT contract address format: {:?}", e);
+            e
+        })?;
+
+        let contract_type = match ctx
+            .contract_manager
+            .write()
+            .await
+            .identify_contract(contract_addr, block_timestamp, chain_id)
+            .await
+        {
+            Ok(info) => info,
+            Err(e) => {
+                error!(
+                    "Error while identifying contract {}: {:?}",
+                    token_sale_event.nft_contract_address, e
+                );
+                return Ok(());
+            }
+        };
+
+        if contract_type != ContractType::ERC721 {
+            debug!(
+                "Contract is not an ERC271 NFT: {}",
+                token_sale_event.nft_contract_address
+            );
+            return Ok(());
+        }
+
+        token_sale_event.nft_type = Some(contract_type.to_string());
+        ctx.event_manager
+            .register_sale_event(&token_sale_event, block_timestamp)
+            .await?;
+
+        Ok(())
+    }
+}
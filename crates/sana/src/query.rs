@@ -0,0 +1,187 @@
+//! Read-side query API over already-indexed sales and token events.
+//!
+//! Indexing only ever writes; anything that wants to list or page through
+//! what's been indexed (an HTTP handler, a GraphQL resolver, a CLI) goes
+//! through [`QueryApi`] instead of reaching into [`Storage`] directly, so a
+//! transport layer can sit on top without the indexer owning one itself.
+//!
+//! [`StorageQuery`] only adapts `Storage::list_sales`/`list_token_events`
+//! to cursor-encoded pages; the transactional upsert/revert semantics for
+//! the rows those methods read (balances, sale/transfer events, block
+//! hashes) live on each `Storage` implementation and ship alongside this
+//! module, not in it.
+
+use crate::storage::types::{ContractType, TokenEvent, TokenSaleEvent};
+use crate::storage::Storage;
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Marketplace a sale was sourced from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarketplaceSource {
+    Element,
+    Ventory,
+}
+
+/// Sort direction for a query's ordering field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+/// Field a [`SalesFilter`] query can be ordered by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SalesOrderBy {
+    Timestamp,
+    Price,
+}
+
+/// Narrows a [`QueryApi::list_sales`] query. Every field is optional; an
+/// unset field matches every row.
+#[derive(Debug, Clone, Default)]
+pub struct SalesFilter {
+    pub contract_address: Option<String>,
+    pub marketplace: Option<MarketplaceSource>,
+    pub nft_type: Option<ContractType>,
+}
+
+/// Narrows a [`QueryApi::list_token_events`] query to a contract and,
+/// optionally, a single token id within it.
+#[derive(Debug, Clone)]
+pub struct TokenEventsFilter {
+    pub contract_address: String,
+    pub token_id: Option<String>,
+}
+
+/// Opaque cursor over a `(timestamp, tx_hash)` pair, the same ordering key
+/// every paginated query uses to break ties between same-timestamp rows.
+/// Callers should treat the encoded form as opaque and round-trip it as-is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cursor {
+    pub timestamp: u64,
+    pub tx_hash: String,
+}
+
+impl Cursor {
+    pub fn encode(&self) -> String {
+        hex_encode(format!("{}:{}", self.timestamp, self.tx_hash).as_bytes())
+    }
+
+    pub fn decode(raw: &str) -> Result<Self> {
+        let decoded = String::from_utf8(hex_decode(raw)?)?;
+        let (timestamp, tx_hash) = decoded
+            .split_once(':')
+            .ok_or_else(|| anyhow!("malformed pagination cursor"))?;
+
+        Ok(Self {
+            timestamp: timestamp.parse()?,
+            tx_hash: tx_hash.to_string(),
+        })
+    }
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn hex_decode(s: &str) -> Result<Vec<u8>> {
+    if s.len() % 2 != 0 {
+        return Err(anyhow!("malformed pagination cursor"));
+    }
+
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).map_err(|e| anyhow!(e)))
+        .collect()
+}
+
+/// A page request: an opaque cursor from a previous [`PagedResult`] (or
+/// `None` for the first page) and how many rows to return.
+#[derive(Debug, Clone)]
+pub struct Page {
+    pub cursor: Option<String>,
+    pub limit: u32,
+}
+
+/// A page of results plus the cursor to pass back in for the next one.
+/// `next_cursor` is `None` once there's nothing left to page through.
+#[derive(Debug, Clone)]
+pub struct PagedResult<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+}
+
+/// Read side of the indexer: lists sales and token events with ordering,
+/// filtering and cursor pagination, without exposing `Storage` itself.
+#[async_trait]
+pub trait QueryApi: Send + Sync {
+    async fn list_sales(
+        &self,
+        filter: SalesFilter,
+        order_by: SalesOrderBy,
+        order: SortOrder,
+        page: Page,
+    ) -> Result<PagedResult<TokenSaleEvent>>;
+
+    async fn list_token_events(
+        &self,
+        filter: TokenEventsFilter,
+        order: SortOrder,
+        page: Page,
+    ) -> Result<PagedResult<TokenEvent>>;
+}
+
+/// Default [`QueryApi`] backed directly by a [`Storage`] implementation.
+pub struct StorageQuery<S: Storage> {
+    storage: Arc<S>,
+}
+
+impl<S: Storage> StorageQuery<S> {
+    pub fn new(storage: Arc<S>) -> Self {
+        Self { storage }
+    }
+}
+
+#[async_trait]
+impl<S: Storage> QueryApi for StorageQuery<S> {
+    async fn list_sales(
+        &self,
+        filter: SalesFilter,
+        order_by: SalesOrderBy,
+        order: SortOrder,
+        page: Page,
+    ) -> Result<PagedResult<TokenSaleEvent>> {
+        let cursor = page.cursor.as_deref().map(Cursor::decode).transpose()?;
+
+        let (items, next) = self
+            .storage
+            .list_sales(filter, order_by, order, cursor, page.limit)
+            .await?;
+
+        Ok(PagedResult {
+            items,
+            next_cursor: next.map(|c| c.encode()),
+        })
+    }
+
+    async fn list_token_events(
+        &self,
+        filter: TokenEventsFilter,
+        order: SortOrder,
+        page: Page,
+    ) -> Result<PagedResult<TokenEvent>> {
+        let cursor = page.cursor.as_deref().map(Cursor::decode).transpose()?;
+
+        let (items, next) = self
+            .storage
+            .list_token_events(filter, order, cursor, page.limit)
+            .await?;
+
+        Ok(PagedResult {
+            items,
+            next_cursor: next.map(|c| c.encode()),
+        })
+    }
+}